@@ -16,7 +16,9 @@ pub(super) struct AnimationDto {
     #[serde(default)]
     mode: ModeDto,
     #[serde(default)]
-    frame_duration: Option<u64>,
+    frame_duration: Option<DurationDto>,
+    #[serde(default)]
+    fps: Option<f64>,
     frames: Vec<FrameDto>,
 }
 
@@ -26,6 +28,9 @@ enum ModeDto {
     RepeatFrom(usize),
     Once,
     PingPong,
+    #[serde(alias = "repeat-count")]
+    RepeatCount(usize),
+    Reverse,
 }
 
 impl Default for ModeDto {
@@ -34,9 +39,12 @@ impl Default for ModeDto {
     }
 }
 
+/// A single entry of the `frames` list, already expanded to the one or more
+/// frame indices it represents (a plain index, a `range`, and/or a `repeat`
+/// count all collapse down to this).
 struct FrameDto {
-    index: usize,
-    duration: Option<u64>,
+    indices: Vec<usize>,
+    duration: Option<Duration>,
 }
 
 impl<'de> Deserialize<'de> for FrameDto {
@@ -49,15 +57,21 @@ impl<'de> Deserialize<'de> for FrameDto {
         #[derive(Deserialize)]
         #[serde(deny_unknown_fields)]
         struct FrameDtoMap {
-            index: usize,
-            duration: Option<u64>,
+            index: Option<usize>,
+            range: Option<Vec<usize>>,
+            duration: Option<DurationDto>,
+            repeat: Option<usize>,
         }
 
         impl<'de> de::Visitor<'de> for Visitor {
             type Value = FrameDto;
 
             fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-                write!(formatter, "either a frame index, or a frame-index with a")
+                write!(
+                    formatter,
+                    "either a frame index, or a map with an `index` or `range`, \
+                     and optional `duration`/`repeat`"
+                )
             }
 
             fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
@@ -66,7 +80,7 @@ impl<'de> Deserialize<'de> for FrameDto {
             {
                 v.try_into()
                     .map(|index| FrameDto {
-                        index,
+                        indices: vec![index],
                         duration: None,
                     })
                     .map_err(|_| de::Error::invalid_value(Unexpected::Unsigned(v), &self))
@@ -76,35 +90,209 @@ impl<'de> Deserialize<'de> for FrameDto {
             where
                 A: MapAccess<'de>,
             {
-                let FrameDtoMap { index, duration } =
-                    FrameDtoMap::deserialize(MapAccessDeserializer::new(map))?;
-                Ok(FrameDto { index, duration })
+                let FrameDtoMap {
+                    index,
+                    range,
+                    duration,
+                    repeat,
+                } = FrameDtoMap::deserialize(MapAccessDeserializer::new(map))?;
+                let base_indices = match (index, range) {
+                    (Some(index), None) => vec![index],
+                    (None, Some(range)) => match range.as_slice() {
+                        [start, end] if start <= end => (*start..=*end).collect(),
+                        [start, end] => (*end..=*start).rev().collect(),
+                        _ => {
+                            return Err(de::Error::custom(
+                                "`range` must contain exactly two indices: [start, end]",
+                            ))
+                        }
+                    },
+                    (Some(_), Some(_)) => {
+                        return Err(de::Error::custom(
+                            "a frame cannot specify both `index` and `range`",
+                        ))
+                    }
+                    (None, None) => {
+                        return Err(de::Error::custom(
+                            "a frame must specify either `index` or `range`",
+                        ))
+                    }
+                };
+                let repeat = repeat.unwrap_or(1);
+                if repeat == 0 {
+                    return Err(de::Error::custom("`repeat` must be greater than 0"));
+                }
+                let expanded_len = base_indices.len().checked_mul(repeat).ok_or_else(|| {
+                    de::Error::custom("`range`/`repeat` combination is too large")
+                })?;
+                let mut indices = Vec::new();
+                indices.try_reserve_exact(expanded_len).map_err(|_| {
+                    de::Error::custom("`range`/`repeat` combination is too large")
+                })?;
+                indices.extend(base_indices.iter().copied().cycle().take(expanded_len));
+                Ok(FrameDto {
+                    indices,
+                    duration: duration.map(|DurationDto(duration)| duration),
+                })
+            }
+        }
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
+/// A duration that can be deserialized either as a bare `u64` (milliseconds,
+/// for backward compatibility) or as a human-readable string such as
+/// `"100ms"`, `"1.5s"`, `"250us"` or `"1m"`.
+struct DurationDto(Duration);
+
+impl<'de> Deserialize<'de> for DurationDto {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = DurationDto;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(
+                    formatter,
+                    "a duration in milliseconds, or a string such as \"100ms\", \"1.5s\" or \"1m\""
+                )
+            }
+
+            fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(DurationDto(Duration::from_millis(v)))
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                parse_duration_str(v)
+                    .map(DurationDto)
+                    .map_err(de::Error::custom)
+            }
+
+            fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                self.visit_str(v)
             }
         }
         deserializer.deserialize_any(Visitor)
     }
 }
 
+/// Parses a string such as `"100ms"`, `"1.5s"`, `"250us"` or `"1m"` into a
+/// [`Duration`], supporting fractional values for sub-unit precision.
+fn parse_duration_str(s: &str) -> Result<Duration, DurationParseError> {
+    let s = s.trim();
+    if s.is_empty() {
+        return Err(DurationParseError::Empty);
+    }
+    let split_at = s
+        .find(|c: char| !c.is_ascii_digit() && c != '.' && c != '-')
+        .unwrap_or(s.len());
+    let (number, unit) = s.split_at(split_at);
+    let value: f64 = number
+        .parse()
+        .map_err(|_| DurationParseError::InvalidNumber)?;
+    if value < 0.0 {
+        return Err(DurationParseError::Negative);
+    }
+    let nanos = match unit {
+        "ns" => value,
+        "us" | "µs" => value * 1_000.0,
+        "ms" | "" => value * 1_000_000.0,
+        "s" => value * 1_000_000_000.0,
+        "m" => value * 60_000_000_000.0,
+        other => return Err(DurationParseError::UnknownUnit(other.to_owned())),
+    };
+    if nanos <= 0.0 {
+        return Err(DurationParseError::Zero);
+    }
+    Ok(Duration::from_nanos(nanos.round() as u64))
+}
+
+#[derive(Debug)]
+enum DurationParseError {
+    Empty,
+    Negative,
+    InvalidNumber,
+    UnknownUnit(String),
+    Zero,
+}
+
+impl Display for DurationParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            DurationParseError::Empty => write!(f, "duration string must not be empty"),
+            DurationParseError::Negative => write!(f, "duration must not be negative"),
+            DurationParseError::InvalidNumber => {
+                write!(f, "duration does not start with a valid number")
+            }
+            DurationParseError::UnknownUnit(unit) => {
+                write!(
+                    f,
+                    "unknown duration unit {unit:?}, expected one of: ns, us, ms, s, m"
+                )
+            }
+            DurationParseError::Zero => write!(f, "invalid duration, must be > 0"),
+        }
+    }
+}
+
+impl Error for DurationParseError {}
+
 impl TryFrom<AnimationDto> for SpriteSheetAnimation {
     type Error = InvalidAnimation;
 
     fn try_from(animation: AnimationDto) -> Result<Self, Self::Error> {
+        let frame_duration = match (animation.frame_duration, animation.fps) {
+            (Some(_), Some(_)) => return Err(InvalidAnimation::ConflictingFpsAndFrameDuration),
+            (Some(DurationDto(duration)), None) => Some(duration),
+            (None, Some(fps)) => {
+                if !fps.is_finite() || fps <= 0.0 {
+                    return Err(InvalidAnimation::InvalidFps(fps));
+                }
+                Some(
+                    Duration::try_from_secs_f64(1.0 / fps)
+                        .map_err(|_| InvalidAnimation::InvalidFps(fps))?,
+                )
+            }
+            (None, None) => None,
+        };
         Ok(Self {
             frames: animation
                 .frames
                 .into_iter()
-                .map(|FrameDto { index, duration }| {
-                    match duration.or(animation.frame_duration).filter(|d| *d > 0) {
-                        Some(duration) => Ok(Frame::new(index, Duration::from_millis(duration))),
+                .map(|FrameDto { indices, duration }| {
+                    match duration.or(frame_duration).filter(|d| !d.is_zero()) {
+                        Some(duration) => Ok(indices
+                            .into_iter()
+                            .map(|index| Frame::new(index, duration))
+                            .collect::<Vec<_>>()),
                         None => Err(InvalidAnimation::ZeroDuration),
                     }
                 })
-                .collect::<Result<_, _>>()?,
+                .collect::<Result<Vec<_>, _>>()?
+                .into_iter()
+                .flatten()
+                .collect(),
             mode: match animation.mode {
                 ModeDto::Repeat => Mode::RepeatFrom(0),
                 ModeDto::RepeatFrom(f) => Mode::RepeatFrom(f),
                 ModeDto::Once => Mode::Once,
                 ModeDto::PingPong => Mode::PingPong,
+                ModeDto::RepeatCount(0) => return Err(InvalidAnimation::ZeroRepeatCount),
+                ModeDto::RepeatCount(n) => Mode::RepeatCount(n),
+                ModeDto::Reverse => Mode::Reverse,
             },
         })
     }
@@ -113,12 +301,25 @@ impl TryFrom<AnimationDto> for SpriteSheetAnimation {
 #[derive(Debug)]
 pub(super) enum InvalidAnimation {
     ZeroDuration,
+    ConflictingFpsAndFrameDuration,
+    ZeroRepeatCount,
+    InvalidFps(f64),
 }
 
 impl Display for InvalidAnimation {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
             InvalidAnimation::ZeroDuration => write!(f, "invalid duration, must be > 0"), /*  */
+            InvalidAnimation::ConflictingFpsAndFrameDuration => write!(
+                f,
+                "`fps` and `frame_duration` are mutually exclusive, specify only one"
+            ),
+            InvalidAnimation::ZeroRepeatCount => {
+                write!(f, "invalid repeat-count, must be > 0")
+            }
+            InvalidAnimation::InvalidFps(fps) => {
+                write!(f, "invalid fps {fps}, must be a finite number > 0")
+            }
         }
     }
 }
@@ -131,8 +332,9 @@ impl SpriteSheetAnimation {
     /// # Yaml schema
     ///
     /// ```yaml
-    /// # The mode can be one of: 'once', 'repeat', 'ping-pong'
+    /// # The mode can be one of: 'Once', 'Repeat', 'PingPong', 'Reverse'
     /// # or 'repeat-from: n' (where 'n' is the frame-index to repeat from)
+    /// # or 'repeat-count: n' (where 'n' is the number of times to repeat)
     /// # The default is 'Repeat'
     /// mode: PingPong
     /// frames:
@@ -150,6 +352,21 @@ impl SpriteSheetAnimation {
     /// frames: [0, 1, 2] # sequence of frame indices
     /// ```
     ///
+    /// Durations may also be written as a human-readable string, e.g. `"100ms"`,
+    /// `"1.5s"` or `"1m"`, instead of a plain number of milliseconds.
+    ///
+    /// As an alternative to `frame_duration`, `fps` sets the duration of every
+    /// frame from a frames-per-second value; the two are mutually exclusive.
+    ///
+    /// Frame entries also accept a `range: [start, end]` (reversed if
+    /// `start > end`) instead of a plain `index`, and a `repeat: n` to
+    /// duplicate an index or range `n` times in sequence:
+    /// ```yaml
+    /// frames:
+    ///   - range: [4, 9]
+    ///   - { index: 0, repeat: 3 }
+    /// ```
+    ///
     /// # Errors
     ///
     /// Returns an error if the content is not a valid yaml representation of an animation
@@ -163,8 +380,9 @@ impl SpriteSheetAnimation {
     /// # Yaml schema
     ///
     /// ```yaml
-    /// # The mode can be one of: 'Once', 'Repeat', 'PingPong'
+    /// # The mode can be one of: 'Once', 'Repeat', 'PingPong', 'Reverse'
     /// # or 'RepeatFrom: n' (where 'n' is the frame-index to repeat from)
+    /// # or 'repeat-count: n' (where 'n' is the number of times to repeat)
     /// # The default is 'Repeat'
     /// mode: PingPong
     /// frames:
@@ -182,6 +400,21 @@ impl SpriteSheetAnimation {
     /// frames: [0, 1, 2] # sequence of frame indices
     /// ```
     ///
+    /// Durations may also be written as a human-readable string, e.g. `"100ms"`,
+    /// `"1.5s"` or `"1m"`, instead of a plain number of milliseconds.
+    ///
+    /// As an alternative to `frame_duration`, `fps` sets the duration of every
+    /// frame from a frames-per-second value; the two are mutually exclusive.
+    ///
+    /// Frame entries also accept a `range: [start, end]` (reversed if
+    /// `start > end`) instead of a plain `index`, and a `repeat: n` to
+    /// duplicate an index or range `n` times in sequence:
+    /// ```yaml
+    /// frames:
+    ///   - range: [4, 9]
+    ///   - { index: 0, repeat: 3 }
+    /// ```
+    ///
     /// # Errors
     ///
     /// Returns an error if the content is not a valid yaml representation of an animation
@@ -196,8 +429,9 @@ impl SpriteSheetAnimation {
     ///
     /// ```ron
     /// (
-    ///   // The mode can be one of: 'Once', 'Repeat', 'PingPong'
+    ///   // The mode can be one of: 'Once', 'Repeat', 'PingPong', 'Reverse'
     ///   // or 'RepeatFrom(n)' (where 'n' is the frame-index to repeat from)
+    ///   // or 'RepeatCount(n)' (where 'n' is the number of times to repeat)
     ///   // The default is 'Repeat'
     ///   mode: PingPong,
     ///   frames: [
@@ -225,8 +459,9 @@ impl SpriteSheetAnimation {
     ///
     /// ```ron
     /// (
-    ///   // The mode can be one of: 'Once', 'Repeat', 'PingPong'
+    ///   // The mode can be one of: 'Once', 'Repeat', 'PingPong', 'Reverse'
     ///   // or 'RepeatFrom(n)' (where 'n' is the frame-index to repeat from)
+    ///   // or 'RepeatCount(n)' (where 'n' is the number of times to repeat)
     ///   // The default is 'Repeat'
     ///   mode: PingPong,
     ///   frames: [
@@ -250,6 +485,52 @@ impl SpriteSheetAnimation {
             .from_bytes(ron)
             .map_err(AnimationParseError::new)
     }
+
+    /// Parse content of a json string representing the animation
+    ///
+    /// # Json schema
+    ///
+    /// ```json
+    /// {
+    ///   "mode": "PingPong",
+    ///   "frames": [
+    ///     { "index": 0, "duration": 100 },
+    ///     { "index": 1, "duration": 100 },
+    ///     { "index": 2, "duration": 120 }
+    ///   ]
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the content is not a valid json representation of an animation
+    #[cfg(feature = "json")]
+    pub fn from_json_str(json: &str) -> Result<Self, AnimationParseError> {
+        Self::from_json_bytes(json.as_bytes())
+    }
+
+    /// Parse content of json bytes representing the animation
+    ///
+    /// # Json schema
+    ///
+    /// ```json
+    /// {
+    ///   "mode": "PingPong",
+    ///   "frames": [
+    ///     { "index": 0, "duration": 100 },
+    ///     { "index": 1, "duration": 100 },
+    ///     { "index": 2, "duration": 120 }
+    ///   ]
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the content is not a valid json representation of an animation
+    #[cfg(feature = "json")]
+    pub fn from_json_bytes(json: &[u8]) -> Result<Self, AnimationParseError> {
+        serde_json::from_slice(json).map_err(AnimationParseError::new)
+    }
 }
 
 #[derive(Debug)]
@@ -434,6 +715,441 @@ mod tests {
                 ]
             );
         }
+
+        #[test]
+        fn duration_as_human_readable_string() {
+            // given
+            let content = r#"
+            frames:
+              - index: 0
+                duration: "100ms"
+              - index: 1
+                duration: "1.5s"
+              - index: 2
+                duration: "250us"
+              - index: 3
+                duration: "1m"
+        "#;
+
+            // when
+            let animation = SpriteSheetAnimation::from_yaml_str(content).unwrap();
+
+            // then
+            assert_eq!(
+                animation.frames,
+                vec![
+                    Frame::new(0, Duration::from_millis(100)),
+                    Frame::new(1, Duration::from_millis(1500)),
+                    Frame::new(2, Duration::from_micros(250)),
+                    Frame::new(3, Duration::from_secs(60)),
+                ]
+            );
+        }
+
+        #[test]
+        fn frame_duration_as_human_readable_string() {
+            // given
+            let content = r#"
+            frame_duration: "100ms"
+            frames: [0, 1, 2]
+        "#;
+
+            // when
+            let animation = SpriteSheetAnimation::from_yaml_str(content).unwrap();
+
+            // then
+            assert_eq!(
+                animation.frames,
+                vec![
+                    Frame::new(0, Duration::from_millis(100)),
+                    Frame::new(1, Duration::from_millis(100)),
+                    Frame::new(2, Duration::from_millis(100)),
+                ]
+            );
+        }
+
+        #[test]
+        fn duration_string_empty() {
+            // given
+            let content = r#"
+            frames:
+              - index: 0
+                duration: ""
+        "#;
+
+            // when
+            let animation = SpriteSheetAnimation::from_yaml_str(content);
+
+            // then
+            assert!(animation.is_err());
+        }
+
+        #[test]
+        fn duration_string_negative() {
+            // given
+            let content = r#"
+            frames:
+              - index: 0
+                duration: "-100ms"
+        "#;
+
+            // when
+            let animation = SpriteSheetAnimation::from_yaml_str(content);
+
+            // then
+            assert!(animation.is_err());
+        }
+
+        #[test]
+        fn duration_string_unknown_unit() {
+            // given
+            let content = r#"
+            frames:
+              - index: 0
+                duration: "100fortnights"
+        "#;
+
+            // when
+            let animation = SpriteSheetAnimation::from_yaml_str(content);
+
+            // then
+            assert!(animation.is_err());
+        }
+
+        #[test]
+        fn duration_string_zero() {
+            // given
+            let content = r#"
+            frames:
+              - index: 0
+                duration: "0s"
+        "#;
+
+            // when
+            let animation = SpriteSheetAnimation::from_yaml_str(content);
+
+            // then
+            assert!(animation.is_err());
+        }
+
+        #[test]
+        fn fps_sets_duration_for_all_frames() {
+            // given
+            let content = "
+            fps: 10
+            frames: [0, 1, 2]
+        ";
+
+            // when
+            let animation = SpriteSheetAnimation::from_yaml_str(content).unwrap();
+
+            // then
+            assert_eq!(
+                animation.frames,
+                vec![
+                    Frame::new(0, Duration::from_millis(100)),
+                    Frame::new(1, Duration::from_millis(100)),
+                    Frame::new(2, Duration::from_millis(100)),
+                ]
+            );
+        }
+
+        #[test]
+        fn fps_is_overridden_by_explicit_frame_duration() {
+            // given
+            let content = "
+            fps: 10
+            frames:
+              - index: 0
+              - index: 1
+                duration: 500
+        ";
+
+            // when
+            let animation = SpriteSheetAnimation::from_yaml_str(content).unwrap();
+
+            // then
+            assert_eq!(
+                animation.frames,
+                vec![
+                    Frame::new(0, Duration::from_millis(100)),
+                    Frame::new(1, Duration::from_millis(500)),
+                ]
+            );
+        }
+
+        #[test]
+        fn fps_conflicts_with_frame_duration() {
+            // given
+            let content = "
+            fps: 10
+            frame_duration: 100
+            frames: [0, 1, 2]
+        ";
+
+            // when
+            let animation = SpriteSheetAnimation::from_yaml_str(content);
+
+            // then
+            assert!(animation.is_err());
+        }
+
+        #[test]
+        fn zero_fps_is_rejected() {
+            // given
+            let content = "
+            fps: 0
+            frames: [0, 1, 2]
+        ";
+
+            // when
+            let animation = SpriteSheetAnimation::from_yaml_str(content);
+
+            // then
+            assert!(animation.is_err());
+        }
+
+        #[test]
+        fn negative_fps_is_rejected() {
+            // given
+            let content = "
+            fps: -5
+            frames: [0, 1, 2]
+        ";
+
+            // when
+            let animation = SpriteSheetAnimation::from_yaml_str(content);
+
+            // then
+            assert!(animation.is_err());
+        }
+
+        #[test]
+        fn tiny_fps_is_rejected_instead_of_overflowing() {
+            // given
+            let content = "
+            fps: 1e-300
+            frames: [0, 1, 2]
+        ";
+
+            // when
+            let animation = SpriteSheetAnimation::from_yaml_str(content);
+
+            // then
+            assert!(animation.is_err());
+        }
+
+        #[test]
+        fn frame_range() {
+            // given
+            let content = "
+            frame_duration: 100
+            frames:
+              - range: [4, 9]
+        ";
+
+            // when
+            let animation = SpriteSheetAnimation::from_yaml_str(content).unwrap();
+
+            // then
+            assert_eq!(
+                animation.frames,
+                vec![4, 5, 6, 7, 8, 9]
+                    .into_iter()
+                    .map(|index| Frame::new(index, Duration::from_millis(100)))
+                    .collect::<Vec<_>>()
+            );
+        }
+
+        #[test]
+        fn frame_range_reversed() {
+            // given
+            let content = "
+            frame_duration: 100
+            frames:
+              - range: [9, 4]
+        ";
+
+            // when
+            let animation = SpriteSheetAnimation::from_yaml_str(content).unwrap();
+
+            // then
+            assert_eq!(
+                animation.frames,
+                vec![9, 8, 7, 6, 5, 4]
+                    .into_iter()
+                    .map(|index| Frame::new(index, Duration::from_millis(100)))
+                    .collect::<Vec<_>>()
+            );
+        }
+
+        #[test]
+        fn frame_repeat() {
+            // given
+            let content = "
+            frame_duration: 100
+            frames:
+              - index: 3
+                repeat: 3
+        ";
+
+            // when
+            let animation = SpriteSheetAnimation::from_yaml_str(content).unwrap();
+
+            // then
+            assert_eq!(
+                animation.frames,
+                vec![
+                    Frame::new(3, Duration::from_millis(100)),
+                    Frame::new(3, Duration::from_millis(100)),
+                    Frame::new(3, Duration::from_millis(100)),
+                ]
+            );
+        }
+
+        #[test]
+        fn frame_range_with_repeat() {
+            // given
+            let content = "
+            frame_duration: 100
+            frames:
+              - range: [0, 1]
+                repeat: 2
+        ";
+
+            // when
+            let animation = SpriteSheetAnimation::from_yaml_str(content).unwrap();
+
+            // then
+            assert_eq!(
+                animation.frames,
+                vec![0, 1, 0, 1]
+                    .into_iter()
+                    .map(|index| Frame::new(index, Duration::from_millis(100)))
+                    .collect::<Vec<_>>()
+            );
+        }
+
+        #[test]
+        fn frame_range_must_have_exactly_two_indices() {
+            // given
+            let content = "
+            frame_duration: 100
+            frames:
+              - range: [0]
+        ";
+
+            // when
+            let animation = SpriteSheetAnimation::from_yaml_str(content);
+
+            // then
+            assert!(animation.is_err());
+        }
+
+        #[test]
+        fn frame_repeat_zero_is_rejected() {
+            // given
+            let content = "
+            frame_duration: 100
+            frames:
+              - index: 0
+                repeat: 0
+        ";
+
+            // when
+            let animation = SpriteSheetAnimation::from_yaml_str(content);
+
+            // then
+            assert!(animation.is_err());
+        }
+
+        #[test]
+        fn frame_repeat_overflow_is_rejected() {
+            // given
+            let content = "
+            frame_duration: 100
+            frames:
+              - index: 0
+                repeat: 18446744073709551615
+        ";
+
+            // when
+            let animation = SpriteSheetAnimation::from_yaml_str(content);
+
+            // then
+            assert!(animation.is_err());
+        }
+
+        #[test]
+        fn repeat_count() {
+            // given
+            let content = "
+            mode:
+              RepeatCount: 3
+            frames:
+              - index: 0
+                duration: 100
+        ";
+
+            // when
+            let animation = SpriteSheetAnimation::from_yaml_str(content).unwrap();
+
+            // then
+            assert_eq!(animation.mode, Mode::RepeatCount(3));
+        }
+
+        #[test]
+        fn repeat_count_kebab_case_alias() {
+            // given
+            let content = "
+            mode:
+              repeat-count: 3
+            frames:
+              - index: 0
+                duration: 100
+        ";
+
+            // when
+            let animation = SpriteSheetAnimation::from_yaml_str(content).unwrap();
+
+            // then
+            assert_eq!(animation.mode, Mode::RepeatCount(3));
+        }
+
+        #[test]
+        fn repeat_count_zero_is_rejected() {
+            // given
+            let content = "
+            mode:
+              RepeatCount: 0
+            frames:
+              - index: 0
+                duration: 100
+        ";
+
+            // when
+            let animation = SpriteSheetAnimation::from_yaml_str(content);
+
+            // then
+            assert!(animation.is_err());
+        }
+
+        #[test]
+        fn reverse() {
+            // given
+            let content = "
+            mode: Reverse
+            frames:
+              - index: 0
+                duration: 100
+        ";
+
+            // when
+            let animation = SpriteSheetAnimation::from_yaml_str(content).unwrap();
+
+            // then
+            assert_eq!(animation.mode, Mode::Reverse);
+        }
     }
 
     #[cfg(feature = "ron")]
@@ -471,4 +1187,60 @@ mod tests {
             );
         }
     }
+
+    #[cfg(feature = "json")]
+    mod json {
+        use super::*;
+
+        #[test]
+        fn frames() {
+            // given
+            let content = r#"
+            {
+                "mode": { "RepeatFrom": 1 },
+                "frames": [
+                    { "index": 0, "duration": 100 },
+                    { "index": 1, "duration": 100 },
+                    { "index": 2, "duration": 120 }
+                ]
+            }"#;
+
+            // when
+            let animation = SpriteSheetAnimation::from_json_str(content).unwrap();
+
+            // then
+            assert_eq!(animation.mode, Mode::RepeatFrom(1));
+            assert_eq!(
+                animation.frames,
+                vec![
+                    Frame::new(0, Duration::from_millis(100)),
+                    Frame::new(1, Duration::from_millis(100)),
+                    Frame::new(2, Duration::from_millis(120)),
+                ]
+            );
+        }
+
+        #[test]
+        fn same_duration_for_all_frames() {
+            // given
+            let content = r#"
+            {
+                "frame_duration": 100,
+                "frames": [0, 1, 2]
+            }"#;
+
+            // when
+            let animation = SpriteSheetAnimation::from_json_str(content).unwrap();
+
+            // then
+            assert_eq!(
+                animation.frames,
+                vec![
+                    Frame::new(0, Duration::from_millis(100)),
+                    Frame::new(1, Duration::from_millis(100)),
+                    Frame::new(2, Duration::from_millis(100)),
+                ]
+            );
+        }
+    }
 }